@@ -1,3 +1,9 @@
+use std::pin::Pin;
+
+mod set;
+
+pub use set::QuantifiedSet;
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Quantified<T> {
     None,
@@ -107,6 +113,128 @@ impl<T: std::ops::Deref> Quantified<T> {
     }
 }
 
+impl<T> Quantified<T> {
+    /// Returns `true` if `self` is `None`, i.e. names the empty set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use quantified::Quantified;
+    /// assert_eq!(Quantified::<i32>::None.is_empty(), true);
+    /// assert_eq!(Quantified::Some(2).is_empty(), false);
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Self::None)
+    }
+
+    /// Returns `true` if `self` is `All`, i.e. names the universe.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use quantified::Quantified;
+    /// assert_eq!(Quantified::<i32>::All.is_universal(), true);
+    /// assert_eq!(Quantified::Some(2).is_universal(), false);
+    /// ```
+    pub fn is_universal(&self) -> bool {
+        matches!(self, Self::All)
+    }
+}
+
+impl<T: PartialEq> Quantified<T> {
+    /// Returns `true` if the set named by `self` contains `value`.
+    ///
+    /// `None` contains nothing, `All` contains everything, `Some(x)` contains
+    /// only `x`, and `Excluding(x)` contains everything except `x`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use quantified::Quantified;
+    /// assert_eq!(Quantified::Some(2).contains(&2), true);
+    /// assert_eq!(Quantified::Some(2).contains(&3), false);
+    /// assert_eq!(Quantified::Excluding(2).contains(&2), false);
+    /// assert_eq!(Quantified::Excluding(2).contains(&3), true);
+    /// assert_eq!(Quantified::<i32>::None.contains(&2), false);
+    /// assert_eq!(Quantified::<i32>::All.contains(&2), true);
+    /// ```
+    pub fn contains(&self, value: &T) -> bool {
+        match self {
+            Self::None => false,
+            Self::All => true,
+            Self::Some(x) => x == value,
+            Self::Excluding(x) => x != value,
+        }
+    }
+
+    /// Filters an iterator down to the items that `self` contains, i.e. the
+    /// set this `Quantified` names used as a predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use quantified::Quantified;
+    /// let values = vec![1, 2, 3];
+    ///
+    /// assert_eq!(
+    ///     Quantified::Excluding(2).filter_iter(&values).collect::<Vec<_>>(),
+    ///     vec![&1, &3],
+    /// );
+    /// assert_eq!(
+    ///     Quantified::<i32>::All.filter_iter(&values).collect::<Vec<_>>(),
+    ///     vec![&1, &2, &3],
+    /// );
+    /// ```
+    pub fn filter_iter<'a, I: IntoIterator<Item = &'a T>>(
+        &'a self,
+        iter: I,
+    ) -> impl Iterator<Item = &'a T> {
+        iter.into_iter().filter(move |item| self.contains(item))
+    }
+
+    /// Drops the items from `vec` that `self` does not contain, in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use quantified::Quantified;
+    /// let mut values = vec![1, 2, 3];
+    /// Quantified::Excluding(2).retain(&mut values);
+    /// assert_eq!(values, vec![1, 3]);
+    /// ```
+    pub fn retain(&self, vec: &mut Vec<T>) {
+        vec.retain(|item| self.contains(item));
+    }
+}
+
+impl<T> std::ops::Not for Quantified<T> {
+    type Output = Self;
+
+    /// Returns the set complement of `self`.
+    ///
+    /// The variants are closed under complement: `None` and `All` swap with
+    /// each other, and `Some`/`Excluding` swap while keeping the contained
+    /// value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use quantified::Quantified;
+    /// assert_eq!(!Quantified::<i32>::None, Quantified::All);
+    /// assert_eq!(!Quantified::<i32>::All, Quantified::None);
+    /// assert_eq!(!Quantified::Some(2), Quantified::Excluding(2));
+    /// assert_eq!(!Quantified::Excluding(2), Quantified::Some(2));
+    /// ```
+    fn not(self) -> Self::Output {
+        match self {
+            Self::None => Self::All,
+            Self::All => Self::None,
+            Self::Some(x) => Self::Excluding(x),
+            Self::Excluding(x) => Self::Some(x),
+        }
+    }
+}
+
 impl<T: std::ops::DerefMut> Quantified<T> {
     /// Converts from `Quantified<T>` (or `&mut Quantified<T>`) to `Quantified<&mut T::Target>`.
     ///
@@ -127,3 +255,338 @@ impl<T: std::ops::DerefMut> Quantified<T> {
         self.as_mut().map(|t| t.deref_mut())
     }
 }
+
+impl<T> Quantified<T> {
+    /// Converts from `Pin<&Quantified<T>>` to `Quantified<Pin<&T>>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use quantified::Quantified;
+    /// use std::pin::Pin;
+    ///
+    /// let q: Quantified<String> = Quantified::Some("hey".to_owned());
+    /// let pinned: Pin<&Quantified<String>> = Pin::new(&q);
+    /// let projected: Quantified<Pin<&String>> = pinned.as_pin_ref();
+    /// assert_eq!(projected.map(|s| s.len()), Quantified::Some(3));
+    /// ```
+    pub fn as_pin_ref(self: Pin<&Self>) -> Quantified<Pin<&T>> {
+        match Pin::get_ref(self) {
+            Self::Some(x) => Quantified::Some(unsafe { Pin::new_unchecked(x) }),
+            Self::Excluding(x) => Quantified::Excluding(unsafe { Pin::new_unchecked(x) }),
+            Self::None => Quantified::None,
+            Self::All => Quantified::All,
+        }
+    }
+
+    /// Converts from `Pin<&mut Quantified<T>>` to `Quantified<Pin<&mut T>>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use quantified::Quantified;
+    /// use std::pin::Pin;
+    ///
+    /// let mut q: Quantified<String> = Quantified::Some("hey".to_owned());
+    /// let pinned: Pin<&mut Quantified<String>> = Pin::new(&mut q);
+    /// let projected: Quantified<Pin<&mut String>> = pinned.as_pin_mut();
+    /// assert_eq!(
+    ///     projected.map(|mut s| {
+    ///         s.as_mut().make_ascii_uppercase();
+    ///         s
+    ///     }),
+    ///     Quantified::Some(Pin::new(&mut "HEY".to_owned()))
+    /// );
+    /// ```
+    pub fn as_pin_mut(self: Pin<&mut Self>) -> Quantified<Pin<&mut T>> {
+        // SAFETY: `self` is already pinned, and this only projects the pin through
+        // the `Some`/`Excluding` arms, so the returned references uphold the same
+        // pinning guarantee.
+        unsafe {
+            match Pin::get_unchecked_mut(self) {
+                Self::Some(x) => Quantified::Some(Pin::new_unchecked(x)),
+                Self::Excluding(x) => Quantified::Excluding(Pin::new_unchecked(x)),
+                Self::None => Quantified::None,
+                Self::All => Quantified::All,
+            }
+        }
+    }
+}
+
+impl<T> Quantified<T> {
+    /// Returns the contained `Some` or `Excluding` value, consuming `self`, or
+    /// `default` if `self` is `None` or `All`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use quantified::Quantified;
+    /// assert_eq!(Quantified::Some(2).unwrap_or(0), 2);
+    /// assert_eq!(Quantified::Excluding(2).unwrap_or(0), 2);
+    /// assert_eq!(Quantified::None.unwrap_or(0), 0);
+    /// assert_eq!(Quantified::All.unwrap_or(0), 0);
+    /// ```
+    pub fn unwrap_or(self, default: T) -> T {
+        match self {
+            Self::Some(x) | Self::Excluding(x) => x,
+            Self::None | Self::All => default,
+        }
+    }
+
+    /// Returns the contained `Some` or `Excluding` value, consuming `self`, or
+    /// computes it from `f` if `self` is `None` or `All`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use quantified::Quantified;
+    /// assert_eq!(Quantified::Some(2).unwrap_or_else(|| 10), 2);
+    /// assert_eq!(Quantified::None.unwrap_or_else(|| 10), 10);
+    /// ```
+    pub fn unwrap_or_else<F: FnOnce() -> T>(self, f: F) -> T {
+        match self {
+            Self::Some(x) | Self::Excluding(x) => x,
+            Self::None | Self::All => f(),
+        }
+    }
+
+    /// Returns `Quantified::None` if `self` is `None`, `Quantified::All` if
+    /// `self` is `All`, and otherwise calls `f` with the contained `Some` or
+    /// `Excluding` value and returns the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use quantified::Quantified;
+    /// fn halve(x: u32) -> Quantified<u32> {
+    ///     if x % 2 == 0 { Quantified::Some(x / 2) } else { Quantified::None }
+    /// }
+    ///
+    /// assert_eq!(Quantified::Some(4).and_then(halve), Quantified::Some(2));
+    /// assert_eq!(Quantified::Some(3).and_then(halve), Quantified::None);
+    /// assert_eq!(Quantified::<u32>::None.and_then(halve), Quantified::None);
+    /// assert_eq!(Quantified::<u32>::All.and_then(halve), Quantified::All);
+    /// ```
+    pub fn and_then<U, F: FnOnce(T) -> Quantified<U>>(self, f: F) -> Quantified<U> {
+        match self {
+            Self::Some(x) | Self::Excluding(x) => f(x),
+            Self::None => Quantified::None,
+            Self::All => Quantified::All,
+        }
+    }
+
+    /// Returns `self` unless it is `None`, in which case `other` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use quantified::Quantified;
+    /// assert_eq!(Quantified::Some(2).or(Quantified::Some(10)), Quantified::Some(2));
+    /// assert_eq!(Quantified::None.or(Quantified::Some(10)), Quantified::Some(10));
+    /// assert_eq!(Quantified::All.or(Quantified::Some(10)), Quantified::All);
+    /// ```
+    pub fn or(self, other: Self) -> Self {
+        match self {
+            Self::None => other,
+            _ => self,
+        }
+    }
+
+    /// Returns `Quantified::None` unless the contained `Some` or `Excluding`
+    /// value satisfies `predicate`, in which case the original variant is
+    /// returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use quantified::Quantified;
+    /// let is_even = |x: &u32| x % 2 == 0;
+    ///
+    /// assert_eq!(Quantified::Some(4).filter(is_even), Quantified::Some(4));
+    /// assert_eq!(Quantified::Some(3).filter(is_even), Quantified::None);
+    /// assert_eq!(Quantified::Excluding(4).filter(is_even), Quantified::Excluding(4));
+    /// assert_eq!(Quantified::<u32>::None.filter(is_even), Quantified::None);
+    /// ```
+    pub fn filter<P: FnOnce(&T) -> bool>(self, predicate: P) -> Self {
+        match self {
+            Self::Some(x) => {
+                if predicate(&x) {
+                    Self::Some(x)
+                } else {
+                    Self::None
+                }
+            }
+            Self::Excluding(x) => {
+                if predicate(&x) {
+                    Self::Excluding(x)
+                } else {
+                    Self::None
+                }
+            }
+            Self::None | Self::All => Self::None,
+        }
+    }
+
+    /// Transforms the contained `Some` or `Excluding` value into `Ok(value)`,
+    /// and `None`/`All` into `Err(err)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use quantified::Quantified;
+    /// assert_eq!(Quantified::Some(2).ok_or("missing"), Ok(2));
+    /// assert_eq!(Quantified::Excluding(2).ok_or("missing"), Ok(2));
+    /// assert_eq!(Quantified::<u32>::None.ok_or("missing"), Err("missing"));
+    /// ```
+    pub fn ok_or<E>(self, err: E) -> Result<T, E> {
+        match self {
+            Self::Some(x) | Self::Excluding(x) => Ok(x),
+            Self::None | Self::All => Err(err),
+        }
+    }
+
+    /// Returns an iterator over the contained `Some`/`Excluding` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use quantified::Quantified;
+    /// assert_eq!(Quantified::Some(4).iter().collect::<Vec<_>>(), vec![&4]);
+    /// assert_eq!(Quantified::<u32>::None.iter().collect::<Vec<_>>(), Vec::<&u32>::new());
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            inner: match self {
+                Self::Some(x) | Self::Excluding(x) => Some(x),
+                Self::None | Self::All => None,
+            },
+        }
+    }
+
+    /// Returns a mutable iterator over the contained `Some`/`Excluding` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use quantified::Quantified;
+    /// let mut x = Quantified::Some(4);
+    /// for v in x.iter_mut() {
+    ///     *v += 1;
+    /// }
+    /// assert_eq!(x, Quantified::Some(5));
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            inner: match self {
+                Self::Some(x) | Self::Excluding(x) => Some(x),
+                Self::None | Self::All => None,
+            },
+        }
+    }
+}
+
+/// An iterator over a reference to the `Some`/`Excluding` value contained in
+/// a [`Quantified`].
+///
+/// This struct is created by [`Quantified::iter`].
+#[derive(Debug)]
+pub struct Iter<'a, T> {
+    inner: Option<&'a T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.take()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = usize::from(self.inner.is_some());
+        (len, Some(len))
+    }
+}
+
+/// An iterator over a mutable reference to the `Some`/`Excluding` value
+/// contained in a [`Quantified`].
+///
+/// This struct is created by [`Quantified::iter_mut`].
+#[derive(Debug)]
+pub struct IterMut<'a, T> {
+    inner: Option<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.take()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = usize::from(self.inner.is_some());
+        (len, Some(len))
+    }
+}
+
+/// An iterator over the `Some`/`Excluding` value contained in a [`Quantified`].
+///
+/// This struct is created by the [`IntoIterator`] implementation for
+/// [`Quantified`].
+#[derive(Debug)]
+pub struct IntoIter<T> {
+    inner: Option<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.take()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = usize::from(self.inner.is_some());
+        (len, Some(len))
+    }
+}
+
+impl<T> IntoIterator for Quantified<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Returns a consuming iterator over the `Some`/`Excluding` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use quantified::Quantified;
+    /// assert_eq!(Quantified::Some(4).into_iter().collect::<Vec<_>>(), vec![4]);
+    /// ```
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter {
+            inner: match self {
+                Self::Some(x) | Self::Excluding(x) => Some(x),
+                Self::None | Self::All => None,
+            },
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Quantified<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Quantified<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
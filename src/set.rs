@@ -0,0 +1,243 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::ops::{BitAnd, BitOr, BitXor, Not, Sub};
+
+use crate::Quantified;
+
+/// A set over `T` that, unlike [`Quantified<T>`], is closed under the boolean
+/// set operators: intersection, union, difference, symmetric difference, and
+/// complement.
+///
+/// Internally this is a co-finite set: either an `Included` set naming its
+/// members directly, or an `Excluded` set naming the members of its
+/// complement. This mirrors how [`Quantified::Excluding`] represents "the
+/// universe minus one value", generalized to arbitrary finite exclusions.
+///
+/// [`Quantified<T>`]: crate::Quantified
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum QuantifiedSet<T: Hash + Eq> {
+    /// The set containing exactly the listed values.
+    Included(HashSet<T>),
+    /// The set containing everything except the listed values.
+    Excluded(HashSet<T>),
+}
+
+impl<T: Hash + Eq> QuantifiedSet<T> {
+    /// Returns `true` if the set contains `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use quantified::QuantifiedSet;
+    /// use std::collections::HashSet;
+    /// let s = QuantifiedSet::Included(HashSet::from([1, 2]));
+    /// assert_eq!(s.contains(&1), true);
+    /// assert_eq!(s.contains(&3), false);
+    /// ```
+    pub fn contains(&self, value: &T) -> bool {
+        match self {
+            Self::Included(s) => s.contains(value),
+            Self::Excluded(s) => !s.contains(value),
+        }
+    }
+
+    /// Returns `true` if the set is empty.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::Included(s) => s.is_empty(),
+            Self::Excluded(_) => false,
+        }
+    }
+
+    /// Returns `true` if the set is the universe.
+    pub fn is_universal(&self) -> bool {
+        match self {
+            Self::Included(_) => false,
+            Self::Excluded(s) => s.is_empty(),
+        }
+    }
+
+    /// Converts this set back into a [`Quantified<T>`] if it names the empty
+    /// set, a singleton, a co-singleton, or the universe. Returns `Err(self)`,
+    /// the original set unchanged, if the set has more than one included or
+    /// excluded member, since `Quantified` cannot represent those.
+    ///
+    /// [`Quantified<T>`]: crate::Quantified
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use quantified::{Quantified, QuantifiedSet};
+    /// use std::collections::HashSet;
+    /// let s = QuantifiedSet::Included(HashSet::from([1]));
+    /// assert_eq!(s.try_into_quantified(), Ok(Quantified::Some(1)));
+    ///
+    /// let s: QuantifiedSet<i32> = QuantifiedSet::Included(HashSet::from([1, 2]));
+    /// assert!(s.try_into_quantified().is_err());
+    /// ```
+    pub fn try_into_quantified(mut self) -> Result<Quantified<T>, Self> {
+        match &mut self {
+            Self::Included(s) if s.is_empty() => Ok(Quantified::None),
+            Self::Included(s) if s.len() == 1 => Ok(Quantified::Some(s.drain().next().unwrap())),
+            Self::Excluded(s) if s.is_empty() => Ok(Quantified::All),
+            Self::Excluded(s) if s.len() == 1 => {
+                Ok(Quantified::Excluding(s.drain().next().unwrap()))
+            }
+            _ => Err(self),
+        }
+    }
+}
+
+impl<T: Hash + Eq> From<Quantified<T>> for QuantifiedSet<T> {
+    /// Lifts a [`Quantified<T>`] into the corresponding co-finite set.
+    ///
+    /// [`Quantified<T>`]: crate::Quantified
+    fn from(q: Quantified<T>) -> Self {
+        match q {
+            Quantified::None => Self::Included(HashSet::new()),
+            Quantified::Some(x) => Self::Included(HashSet::from([x])),
+            Quantified::Excluding(x) => Self::Excluded(HashSet::from([x])),
+            Quantified::All => Self::Excluded(HashSet::new()),
+        }
+    }
+}
+
+impl<T: Hash + Eq + Clone> BitAnd for QuantifiedSet<T> {
+    type Output = Self;
+
+    /// Returns the intersection of two sets.
+    ///
+    /// # Examples
+    ///
+    /// `Excluded ∩ Excluded` is the union of the excluded sets:
+    ///
+    /// ```
+    /// # use quantified::QuantifiedSet;
+    /// use std::collections::HashSet;
+    /// let a = QuantifiedSet::Excluded(HashSet::from([1]));
+    /// let b = QuantifiedSet::Excluded(HashSet::from([2]));
+    /// assert_eq!(a & b, QuantifiedSet::Excluded(HashSet::from([1, 2])));
+    /// ```
+    ///
+    /// `Included ∩ Excluded` is the included set minus the excluded one:
+    ///
+    /// ```
+    /// # use quantified::QuantifiedSet;
+    /// use std::collections::HashSet;
+    /// let a = QuantifiedSet::Included(HashSet::from([1, 2, 3]));
+    /// let b = QuantifiedSet::Excluded(HashSet::from([2]));
+    /// assert_eq!(a & b, QuantifiedSet::Included(HashSet::from([1, 3])));
+    /// ```
+    fn bitand(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (Self::Included(a), Self::Included(b)) => {
+                Self::Included(a.intersection(&b).cloned().collect())
+            }
+            (Self::Excluded(a), Self::Excluded(b)) => Self::Excluded(a.union(&b).cloned().collect()),
+            (Self::Included(a), Self::Excluded(b)) | (Self::Excluded(b), Self::Included(a)) => {
+                Self::Included(a.difference(&b).cloned().collect())
+            }
+        }
+    }
+}
+
+impl<T: Hash + Eq + Clone> BitOr for QuantifiedSet<T> {
+    type Output = Self;
+
+    /// Returns the union of two sets.
+    ///
+    /// # Examples
+    ///
+    /// `Included ∪ Included` is the union of the included sets:
+    ///
+    /// ```
+    /// # use quantified::QuantifiedSet;
+    /// use std::collections::HashSet;
+    /// let a = QuantifiedSet::Included(HashSet::from([1, 2]));
+    /// let b = QuantifiedSet::Included(HashSet::from([2, 3]));
+    /// assert_eq!(a | b, QuantifiedSet::Included(HashSet::from([1, 2, 3])));
+    /// ```
+    ///
+    /// `Excluded ∪ Excluded` is the intersection of the excluded sets:
+    ///
+    /// ```
+    /// # use quantified::QuantifiedSet;
+    /// use std::collections::HashSet;
+    /// let a = QuantifiedSet::Excluded(HashSet::from([1, 2]));
+    /// let b = QuantifiedSet::Excluded(HashSet::from([2, 3]));
+    /// assert_eq!(a | b, QuantifiedSet::Excluded(HashSet::from([2])));
+    /// ```
+    fn bitor(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (Self::Included(a), Self::Included(b)) => Self::Included(a.union(&b).cloned().collect()),
+            (Self::Excluded(a), Self::Excluded(b)) => {
+                Self::Excluded(a.intersection(&b).cloned().collect())
+            }
+            (Self::Excluded(a), Self::Included(b)) | (Self::Included(b), Self::Excluded(a)) => {
+                Self::Excluded(a.difference(&b).cloned().collect())
+            }
+        }
+    }
+}
+
+impl<T: Hash + Eq + Clone> Sub for QuantifiedSet<T> {
+    type Output = Self;
+
+    /// Returns the difference `self \ rhs`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use quantified::QuantifiedSet;
+    /// use std::collections::HashSet;
+    /// let a = QuantifiedSet::Included(HashSet::from([1, 2, 3]));
+    /// let b = QuantifiedSet::Included(HashSet::from([2]));
+    /// assert_eq!(a - b, QuantifiedSet::Included(HashSet::from([1, 3])));
+    /// ```
+    fn sub(self, rhs: Self) -> Self::Output {
+        self & !rhs
+    }
+}
+
+impl<T: Hash + Eq + Clone> BitXor for QuantifiedSet<T> {
+    type Output = Self;
+
+    /// Returns the symmetric difference of two sets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use quantified::QuantifiedSet;
+    /// use std::collections::HashSet;
+    /// let a = QuantifiedSet::Included(HashSet::from([1, 2]));
+    /// let b = QuantifiedSet::Included(HashSet::from([2, 3]));
+    /// assert_eq!(a ^ b, QuantifiedSet::Included(HashSet::from([1, 3])));
+    /// ```
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        (self.clone() - rhs.clone()) | (rhs - self)
+    }
+}
+
+impl<T: Hash + Eq> Not for QuantifiedSet<T> {
+    type Output = Self;
+
+    /// Returns the set complement.
+    ///
+    /// # Examples
+    ///
+    /// De Morgan's law, `!(a & b) == !a | !b`:
+    ///
+    /// ```
+    /// # use quantified::QuantifiedSet;
+    /// use std::collections::HashSet;
+    /// let a = QuantifiedSet::Included(HashSet::from([1, 2]));
+    /// let b = QuantifiedSet::Excluded(HashSet::from([2, 3]));
+    /// assert_eq!(!(a.clone() & b.clone()), !a | !b);
+    /// ```
+    fn not(self) -> Self::Output {
+        match self {
+            Self::Included(s) => Self::Excluded(s),
+            Self::Excluded(s) => Self::Included(s),
+        }
+    }
+}